@@ -8,14 +8,18 @@ use std::{
   collections::HashMap,
   error::Error,
   fmt,
-  fs::{self, DirEntry},
-  io::Error as IoError,
+  fs,
+  path::Path,
+  sync::{Arc, RwLock},
 };
 
-use fluent_bundle::{bundle::FluentBundle, FluentArgs, FluentMessage, FluentResource, FluentValue};
+use fluent_bundle::{
+  bundle::FluentBundle, FluentArgs, FluentError, FluentMessage, FluentResource, FluentValue,
+};
 use intl_memoizer::concurrent::IntlLangMemoizer;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use tracing::{debug, error, info, trace, warn};
-use unic_langid::LanguageIdentifier;
+use unic_langid::{LanguageIdentifier, LanguageIdentifierError};
 
 pub const TRANSLATION_FAILED: &str = "An error has ocurred while trying to translate the message"; // Default error message if translation fails
 
@@ -23,27 +27,131 @@ pub type Bundle = FluentBundle<FluentResource, IntlLangMemoizer>;
 
 // Translator error type
 #[derive(Debug)]
-pub struct TranslatorError {
-  pub description: String,
-  pub name: &'static str,
+pub enum TranslatorError {
+  Io(std::io::Error),
+  LanguageIdentifier(LanguageIdentifierError),
+  Fluent(Vec<FluentError>),
+  MissingMessage(String),
+  DefaultLanguageMissing(String),
+}
+
+impl Error for TranslatorError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      TranslatorError::Io(error) => Some(error),
+      TranslatorError::LanguageIdentifier(error) => Some(error),
+      TranslatorError::Fluent(errors) => {
+        errors.first().map(|error| error as &(dyn Error + 'static))
+      }
+      TranslatorError::MissingMessage(_) | TranslatorError::DefaultLanguageMissing(_) => None,
+    }
+  }
 }
-impl Error for TranslatorError {}
+
 impl fmt::Display for TranslatorError {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let description = &self.description;
-    let name = self.name;
-    write!(f, "{name}: {description}")
+    match self {
+      TranslatorError::Io(error) => write!(f, "IO_ERROR: {error}"),
+      TranslatorError::LanguageIdentifier(error) => {
+        write!(f, "LANGUAGE_IDENTIFIER_ERROR: {error}")
+      }
+      TranslatorError::Fluent(errors) => write!(f, "FLUENT_ERROR: {errors:?}"),
+      TranslatorError::MissingMessage(key) => {
+        write!(f, "MISSING_MESSAGE: no translation found for key {key}")
+      }
+      TranslatorError::DefaultLanguageMissing(language) => write!(
+        f,
+        "DEFAULT_LANGUAGE_ERROR: {language} was designated as default language, but no translations where provided for this language"
+      ),
+    }
+  }
+}
+
+impl From<std::io::Error> for TranslatorError {
+  fn from(error: std::io::Error) -> Self {
+    TranslatorError::Io(error)
+  }
+}
+
+impl From<LanguageIdentifierError> for TranslatorError {
+  fn from(error: LanguageIdentifierError) -> Self {
+    TranslatorError::LanguageIdentifier(error)
   }
 }
 
+impl From<Vec<FluentError>> for TranslatorError {
+  fn from(errors: Vec<FluentError>) -> Self {
+    TranslatorError::Fluent(errors)
+  }
+}
+
+// Expands to the `&[(&str, &[(&str, &str)])]` slice expected by
+// `Translator::from_embedded`, `include_str!`-ing each listed file at build
+// time so translations ship inside the binary with zero runtime IO.
+//
+// ```ignore
+// let languages = embed_translations! {
+//   "en" => ["locales/en/greeting.ftl", "locales/en/errors.ftl"],
+//   "es" => ["locales/es/greeting.ftl"],
+// };
+// let translator = Translator::from_embedded(languages, "en".to_string())?;
+// ```
+#[macro_export]
+macro_rules! embed_translations {
+  ( $( $language:literal => [ $( $file:literal ),* $(,)? ] ),* $(,)? ) => {
+    &[
+      $(
+        (
+          $language,
+          &[ $( ($file, include_str!($file)) ),* ] as &[(&str, &str)],
+        )
+      ),*
+    ] as &[(&str, &[(&str, &str)])]
+  };
+}
+
 pub trait LanguageKey {
   fn as_str(&self) -> &'static str;
 }
 
+// Controls what `MessageTranslator::build` and
+// `Translator::translate_without_args` return when a key has no message (or
+// fails to format) for the negotiated language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingKeyMode {
+  // Returns `TRANSLATION_FAILED` (default, matches the historical behavior)
+  #[default]
+  Placeholder,
+  // Returns the key itself, e.g. `"hello-world"`, making untranslated
+  // strings obvious in development
+  EchoKey,
+  // Surfaces `TranslatorError::MissingMessage` instead of returning a string
+  Error,
+}
+
+// Resolves the outcome for a missing/unformattable message according to
+// `mode`, shared by `Translator::translate_without_args` and
+// `MessageTranslator::build`.
+fn missing_key_outcome<Key: LanguageKey>(
+  mode: MissingKeyMode,
+  key: &Key,
+) -> Result<Cow<'static, str>, TranslatorError> {
+  match mode {
+    MissingKeyMode::Placeholder => Ok(Cow::Borrowed(TRANSLATION_FAILED)),
+    MissingKeyMode::EchoKey => Ok(Cow::Borrowed(key.as_str())),
+    MissingKeyMode::Error => Err(TranslatorError::MissingMessage(key.as_str().to_string())),
+  }
+}
+
 // Translator structure
 pub struct Translator {
   translations: HashMap<String, Bundle>,
+  available_languages: HashMap<String, LanguageIdentifier>,
   default_language: String,
+  missing_key_mode: MissingKeyMode,
+  // The directory `new` loaded from, kept around so `reload` can re-scan it.
+  // Empty for translators built with `from_embedded`.
+  language_directory: String,
 }
 
 // Message translator structure
@@ -52,6 +160,8 @@ pub struct MessageTranslator<'lifetime, Key: LanguageKey> {
   bundle: &'lifetime Bundle,
   message: Option<FluentMessage<'lifetime>>,
   args: Option<FluentArgs<'lifetime>>,
+  missing_key_mode: MissingKeyMode,
+  attribute: Option<&'lifetime str>,
 }
 
 impl Translator {
@@ -62,70 +172,163 @@ impl Translator {
   ) -> Result<Translator, TranslatorError> {
     info!("Loading translations...");
 
+    let (translations, available_languages) = Self::load_directory(language_directory)?;
+
+    info!("Successfully loaded {} languages", translations.len());
+
+    // Checks if translations contains the default message
+    if !translations.contains_key(&default_language) {
+      return Err(TranslatorError::DefaultLanguageMissing(default_language));
+    }
+
+    Ok(Translator {
+      translations,
+      available_languages,
+      default_language,
+      missing_key_mode: MissingKeyMode::default(),
+      language_directory: language_directory.to_string(),
+    })
+  }
+
+  // Scans `language_directory`, returning the translations and parsed
+  // language identifiers found there. Shared by `new` and `reload`.
+  #[allow(clippy::type_complexity)]
+  fn load_directory(
+    language_directory: &str,
+  ) -> Result<(HashMap<String, Bundle>, HashMap<String, LanguageIdentifier>), TranslatorError> {
     // Reads directory files
-    let translations_directory = fs::read_dir(language_directory).map_err(|_| TranslatorError {
-      name: "READ_DIR_ERROR",
-      description: "An error has ocurred while reading translations directory".to_string(),
-    })?;
+    let translations_directory = fs::read_dir(language_directory)?;
 
     // Creates translations hashmap
     let mut translations = HashMap::new();
+    let mut available_languages = HashMap::new();
 
     for result in translations_directory {
-      let directory_data = Self::get_directory_data(result)?; // Skips non directory files and returns it data and name
-      if directory_data.is_none() {
+      let entry = result.expect("Could not get directory entry data");
+      let entry_name = entry.file_name().to_string_lossy().to_string();
+      let file_type = entry.file_type()?;
+
+      // A language can be a directory of (possibly nested) .ftl files, or a
+      // single flat `xx-YY.ftl` file sitting directly in `language_directory`
+      let (language_name, files) = if file_type.is_dir() {
+        (entry_name.clone(), Self::collect_ftl_files(&entry.path())?)
+      } else if let Some(stem) = entry_name.strip_suffix(".ftl") {
+        match fs::read_to_string(entry.path()) {
+          Ok(content) => (stem.to_string(), vec![(entry_name.clone(), content)]),
+          Err(error) => {
+            error!(
+              "An error has ocurred while reading file {}: {}",
+              entry_name, error
+            );
+            continue;
+          }
+        }
+      } else {
+        // Not a directory and not a `.ftl` file: ignore silently (README,
+        // JSON, editor files, ...)
         continue;
+      };
+
+      // Builds the language's bundle out of its collected files
+      if let Some((bundle, language_identifier)) = Self::build_bundle(&language_name, files)? {
+        translations.insert(language_name.clone(), bundle);
+        available_languages.insert(language_name, language_identifier);
       }
+    }
 
-      let (directory, directory_name) = directory_data.unwrap();
+    Ok((translations, available_languages))
+  }
 
-      // Extracts language identifiers from directory's name
-      if let Ok(language_identifier) = directory_name.parse::<LanguageIdentifier>() {
-        debug!("Loading translations for {}", directory_name);
-        let langs = vec![language_identifier];
-        let mut bundle = Bundle::new_concurrent(langs); // Creates new bundle to use locales
+  // Re-scans `language_directory` and atomically swaps in the freshly
+  // loaded translations, picking up spelling fixes or new strings without a
+  // recompile. The new translations are fully built before anything is
+  // swapped in, so a failed reload (an I/O error, a message id defined
+  // twice within a language, or a missing default language) leaves the
+  // previously loaded languages in place. A file that fails to parse is
+  // logged and skipped, as in `new`, and does not fail the reload: its
+  // messages are absent after the swap. Takes `&mut self` so the borrow
+  // checker statically rejects reloading while a `MessageTranslator`
+  // (which borrows a `&Bundle`) is still outstanding.
+  pub fn reload(&mut self) -> Result<(), TranslatorError> {
+    info!("Reloading translations from {}", self.language_directory);
+
+    let (translations, available_languages) = Self::load_directory(&self.language_directory)?;
+
+    if !translations.contains_key(&self.default_language) {
+      return Err(TranslatorError::DefaultLanguageMissing(
+        self.default_language.clone(),
+      ));
+    }
 
-        let language_directory = fs::read_dir(directory.path()).map_err(|_| TranslatorError {
-          name: "READ_DIR_ERROR",
-          description: format!("An error has ocured while trying to read {language_directory}"),
-        })?;
+    info!("Successfully reloaded {} languages", translations.len());
 
-        for file_result in language_directory {
-          let file = Self::get_file_data(file_result);
+    self.translations = translations;
+    self.available_languages = available_languages;
 
-          if file.is_none() {
-            continue;
-          }
+    Ok(())
+  }
+
+  // Watches `language_directory` for filesystem changes, calling `reload`
+  // on the shared translator whenever they occur. Returns the watcher,
+  // which must be kept alive for as long as watching should continue.
+  pub fn watch(translator: Arc<RwLock<Translator>>) -> notify::Result<RecommendedWatcher> {
+    let language_directory = translator
+      .read()
+      .expect("Translator lock was poisoned")
+      .language_directory
+      .clone();
+
+    let watched_directory = language_directory.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+      if let Err(error) = event {
+        error!("Error while watching {}: {}", watched_directory, error);
+        return;
+      }
 
-          let (content, file_name) = file.unwrap();
-
-          // Creates translation resources using file content
-          let resource = FluentResource::try_new(content);
-
-          // Checks if file content is not corrupted
-          match resource {
-            Ok(resource) => {
-              bundle.add_resource(resource).map_err(|_| TranslatorError {
-                name: "BUNDLE_ERROR",
-                description: format!("Could not add data from file {file_name} to bundle"),
-              })?;
-            }
-            Err(error) => {
-              error!(
-                "Corrupt entry encountered in file {} from language {}: {:?}",
-                file_name, directory_name, error.1
-              );
-            }
+      match translator.write() {
+        Ok(mut translator) => {
+          if let Err(error) = translator.reload() {
+            error!(
+              "Failed to reload translations after filesystem change: {}",
+              error
+            );
           }
         }
+        Err(_) => error!("Translator lock was poisoned, skipping reload"),
+      }
+    })?;
 
-        // Adds translations to the hashmap
-        translations.insert(directory_name.to_string(), bundle);
-      } else {
-        warn!(
-          "Ignoring {} as it is not a valid language identifier",
-          directory_name
-        );
+    watcher.watch(Path::new(&language_directory), RecursiveMode::Recursive)?;
+
+    Ok(watcher)
+  }
+
+  // Creates a new translator object from translations embedded at compile
+  // time, e.g. via the `embed_translations!` macro, instead of reading a
+  // directory at runtime. Each `(filename, content)` pair is parsed into a
+  // `FluentResource` and added to the per-language bundle exactly as `new`
+  // does for filesystem-loaded translations, giving single-binary
+  // deployment with zero runtime IO.
+  pub fn from_embedded(
+    languages: &[(&str, &[(&str, &str)])],
+    default_language: String,
+  ) -> Result<Translator, TranslatorError> {
+    info!("Loading embedded translations...");
+
+    // Creates translations hashmap
+    let mut translations = HashMap::new();
+    let mut available_languages = HashMap::new();
+
+    for (language_name, files) in languages {
+      let files = files
+        .iter()
+        .map(|(file_name, content)| (file_name.to_string(), content.to_string()))
+        .collect();
+
+      // Builds the language's bundle out of its embedded files
+      if let Some((bundle, language_identifier)) = Self::build_bundle(language_name, files)? {
+        translations.insert(language_name.to_string(), bundle);
+        available_languages.insert(language_name.to_string(), language_identifier);
       }
     }
 
@@ -133,89 +336,189 @@ impl Translator {
 
     // Checks if translations contains the default message
     if !translations.contains_key(&default_language) {
-      return Err(TranslatorError {
-        name: "DEFAULT_LANGUAGE_ERROR",
-        description: format!("{default_language} was designated as default language, but no translations where provided for this language")
-      });
+      return Err(TranslatorError::DefaultLanguageMissing(default_language));
     }
 
     Ok(Translator {
       translations,
+      available_languages,
       default_language,
+      missing_key_mode: MissingKeyMode::default(),
+      language_directory: String::new(),
     })
   }
 
-  pub fn get_directory_data(
-    directory_result: Result<DirEntry, IoError>,
-  ) -> Result<Option<(DirEntry, String)>, TranslatorError> {
-    let directory = directory_result.expect("Could not get directory data");
-    let directory_name = directory.file_name().to_string_lossy().to_string();
-    if !directory // ignores file if it's not a directory
-      .file_type()
-      .map_err(|_| TranslatorError {
-        name: "READ_FILE_ERROR",
-        description: format!("Could not get file type from {directory_name}"),
-      })?
-      .is_dir()
-    {
-      warn!("Ignoring {} because it is not a directory", directory_name);
-      Ok(None)
-    } else {
-      Ok(Some((directory, directory_name)))
+  // Parses a language's `(filename, content)` pairs into a bundle, shared by
+  // both `new` (filesystem) and `from_embedded` (compile-time) loading.
+  // Returns `None` if `language_name` is not a valid language identifier.
+  fn build_bundle(
+    language_name: &str,
+    files: Vec<(String, String)>,
+  ) -> Result<Option<(Bundle, LanguageIdentifier)>, TranslatorError> {
+    // Extracts language identifiers from the language's name
+    let language_identifier = match language_name.parse::<LanguageIdentifier>() {
+      Ok(language_identifier) => language_identifier,
+      Err(_) => {
+        warn!(
+          "Ignoring {} as it is not a valid language identifier",
+          language_name
+        );
+        return Ok(None);
+      }
+    };
+
+    debug!("Loading translations for {}", language_name);
+    let mut bundle = Bundle::new_concurrent(vec![language_identifier.clone()]); // Creates new bundle to use locales
+
+    for (file_name, content) in files {
+      // Creates translation resources using file content
+      let resource = FluentResource::try_new(content);
+
+      // Checks if file content is not corrupted
+      match resource {
+        Ok(resource) => {
+          bundle.add_resource(resource)?;
+        }
+        Err(error) => {
+          error!(
+            "Corrupt entry encountered in file {} from language {}: {:?}",
+            file_name, language_name, error.1
+          );
+        }
+      }
     }
+
+    Ok(Some((bundle, language_identifier)))
   }
 
-  pub fn get_file_data(file_result: Result<DirEntry, IoError>) -> Option<(String, String)> {
-    let file = file_result.expect("Failed to get file metadata");
-    let file_name = file.file_name().to_string_lossy().to_string();
-    trace!("Loading file {}", file_name);
+  // Builds an ordered fallback chain for a requested language tag, stripping
+  // subtags (`es-MX` -> `es`) and widening to region/script-neutral matches
+  // among the available languages before finally falling back to the
+  // configured default language.
+  pub fn negotiate(&self, requested: &str) -> Vec<&str> {
+    let mut chain: Vec<&str> = Vec::new();
+
+    // Exact match, e.g. `es-MX`
+    Self::push_if_available(&self.available_languages, &mut chain, requested);
+
+    if let Ok(requested_id) = requested.parse::<LanguageIdentifier>() {
+      // Region/script-neutral match, e.g. `es-MX` -> `es`
+      let bare = LanguageIdentifier::from_parts(requested_id.language, None, None, &[]);
+      Self::push_if_available(&self.available_languages, &mut chain, &bare.to_string());
+
+      // Any other available language sharing the same bare language subtag,
+      // e.g. requesting `es-MX` also matches an available `es-AR`. Sorted so
+      // the chain is deterministic across runs instead of depending on
+      // HashMap iteration order.
+      let mut siblings: Vec<&str> = self
+        .available_languages
+        .iter()
+        .filter(|(_, identifier)| identifier.language == requested_id.language)
+        .map(|(candidate, _)| candidate.as_str())
+        .collect();
+      siblings.sort_unstable();
+
+      for candidate in siblings {
+        Self::push_if_available(&self.available_languages, &mut chain, candidate);
+      }
+    }
 
-    match fs::read_to_string(file.path()) {
-      Ok(content) => Some((content, file_name)),
-      Err(error) => {
-        error!(
-          "An error has ocurred while reading file {}: {}",
-          file_name, error
-        );
-        None
+    // Final fallback
+    Self::push_if_available(&self.available_languages, &mut chain, &self.default_language);
+
+    chain
+  }
+
+  // Appends `tag` to `chain` if it's a key of `available`, skipping
+  // duplicates. A plain associated fn (rather than a closure) so the
+  // pushed `&'a str` can be tied to `available`'s lifetime explicitly.
+  fn push_if_available<'a>(
+    available: &'a HashMap<String, LanguageIdentifier>,
+    chain: &mut Vec<&'a str>,
+    tag: &str,
+  ) {
+    if let Some((key, _)) = available.get_key_value(tag) {
+      if !chain.contains(&key.as_str()) {
+        chain.push(key.as_str());
+      }
+    }
+  }
+
+  // Recursively walks `directory`, collecting the contents of every `.ftl`
+  // file found at any nesting depth (e.g. `es/errors.ftl`,
+  // `es/commands/mod.ftl` are both merged into the `es` bundle). Entries
+  // that aren't `.ftl` files are ignored without a warning.
+  fn collect_ftl_files(directory: &Path) -> Result<Vec<(String, String)>, TranslatorError> {
+    let mut files = Vec::new();
+
+    let entries = fs::read_dir(directory)?;
+
+    for result in entries {
+      let entry = result.expect("Could not get directory entry data");
+      let path = entry.path();
+
+      let file_type = entry.file_type()?;
+
+      if file_type.is_dir() {
+        files.extend(Self::collect_ftl_files(&path)?);
+        continue;
+      }
+
+      if path.extension().and_then(|extension| extension.to_str()) != Some("ftl") {
+        continue;
+      }
+
+      let file_name = entry.file_name().to_string_lossy().to_string();
+      trace!("Loading file {}", path.display());
+
+      match fs::read_to_string(&path) {
+        Ok(content) => files.push((file_name, content)),
+        Err(error) => {
+          error!(
+            "An error has ocurred while reading file {}: {}",
+            path.display(),
+            error
+          );
+        }
       }
     }
+
+    Ok(files)
   }
 
-  // Returns the translated message
+  // Returns the translated message, walking the negotiated fallback chain
+  // for `language` until a bundle actually contains the key
   pub fn get_message<'lifetime, Key: LanguageKey>(
     &'lifetime self,
     language: &str,
     key: &Key,
   ) -> (Option<FluentMessage>, &'lifetime Bundle) {
     let translation_key = key.as_str();
-    // Checks if translation language exists
-    let (translations, language) = if let Some(translations) = self.translations.get(language) {
-      (translations, language)
-    } else {
-      debug!(
-        "Attempted to translate to unknown language {}, falling back to {}",
-        language, self.default_language
-      );
-      (
-        self.translations.get(&self.default_language).unwrap(),
-        self.default_language.as_str(),
-      )
-    };
+    let chain = self.negotiate(language);
 
-    // Gets translation message
-    let mut message = translations.get_message(translation_key);
-
-    // Cheks if there's no message and if translation language is equal to the default language
-    if message.is_none() && language != self.default_language {
-      message = self
-        .translations
-        .get(&self.default_language)
-        .unwrap()
-        .get_message(translation_key);
+    for candidate in &chain {
+      if let Some(bundle) = self.translations.get(*candidate) {
+        if let Some(message) = bundle.get_message(translation_key) {
+          return (Some(message), bundle);
+        }
+      }
     }
 
-    (message, translations)
+    debug!(
+      "Could not find {} in negotiated chain {:?} for {}, falling back to {}",
+      translation_key, chain, language, self.default_language
+    );
+
+    let default_bundle = self.translations.get(&self.default_language).unwrap();
+    (default_bundle.get_message(translation_key), default_bundle)
+  }
+
+  // Overrides how `build`/`translate_without_args` behave when a key has no
+  // message (or fails to format) for the negotiated language. Defaults to
+  // `MissingKeyMode::Placeholder`.
+  pub fn with_missing_key_mode(mut self, missing_key_mode: MissingKeyMode) -> Self {
+    self.missing_key_mode = missing_key_mode;
+    self
   }
 
   // Creates the message and returns the message translator structure
@@ -227,34 +530,54 @@ impl Translator {
       bundle,
       message,
       args: Default::default(),
+      missing_key_mode: self.missing_key_mode,
+      attribute: None,
     }
   }
 
   // Translate the message without arguments
-  pub fn translate_without_args<Key: LanguageKey>(&self, language: &str, key: Key) -> Cow<str> {
+  pub fn translate_without_args<Key: LanguageKey>(
+    &self,
+    language: &str,
+    key: Key,
+  ) -> Result<Cow<str>, TranslatorError> {
     let (message, bundle) = self.get_message(language, &key);
     // Checks if it's posible to translate the message
-    if let Some(message) = message {
-      let mut errors = Vec::new();
-      let translated = bundle.format_pattern(message.value().unwrap(), None, &mut errors);
-      // If there's not errors and the language is correct, returns the message
+    let message = match message {
+      Some(message) => message,
+      None => {
+        error!(
+          "Tried to translate non existing language key: {}",
+          key.as_str()
+        );
+        return missing_key_outcome(self.missing_key_mode, &key);
+      }
+    };
 
-      if errors.is_empty() {
-        translated
-      } else {
+    let value = match message.value() {
+      Some(value) => value,
+      None => {
         error!(
-          "Translation failure(s) when translating {} without arguments: {:?}",
-          key.as_str(),
-          errors
+          "Tried to translate key {} which has no value (it may only have attributes)",
+          key.as_str()
         );
-        Cow::Borrowed(TRANSLATION_FAILED)
+        return missing_key_outcome(self.missing_key_mode, &key);
       }
+    };
+
+    let mut errors = Vec::new();
+    let translated = bundle.format_pattern(value, None, &mut errors);
+    // If there's not errors and the language is correct, returns the message
+
+    if errors.is_empty() {
+      Ok(translated)
     } else {
       error!(
-        "Tried to translate non existing language key: {}",
-        key.as_str()
+        "Translation failure(s) when translating {} without arguments: {:?}",
+        key.as_str(),
+        errors
       );
-      Cow::Borrowed(TRANSLATION_FAILED)
+      missing_key_outcome(self.missing_key_mode, &key)
     }
   }
 }
@@ -278,36 +601,390 @@ where
     self
   }
 
+  // Selects a Fluent attribute (e.g. `login.placeholder`, `button.aria-label`)
+  // to format instead of the message's own value. Needed for UI messages
+  // that carry several localized sub-strings under one key.
+  pub fn attribute(mut self, name: &'lifetime str) -> Self {
+    self.attribute = Some(name);
+    self
+  }
+
   // Builds the message
-  pub fn build(&self) -> Cow<str> {
+  pub fn build(&self) -> Result<Cow<'lifetime, str>, TranslatorError> {
     let mut errors = Vec::new();
 
-    match &self.message {
+    let message = match &self.message {
+      Some(message) => message,
       None => {
         error!(
           "Tried to translate non existing language key: {}",
           self.key.as_str()
         );
-        Cow::Borrowed(TRANSLATION_FAILED)
+        return missing_key_outcome(self.missing_key_mode, &self.key);
       }
-      Some(message) => {
-        let translated =
-          self
-            .bundle
-            .format_pattern(message.value().unwrap(), self.args.as_ref(), &mut errors);
-
-        if errors.is_empty() {
-          translated
-        } else {
+    };
+
+    let pattern = match self.attribute {
+      Some(attribute_name) => match message.get_attribute(attribute_name) {
+        Some(attribute) => attribute.value(),
+        None => {
           error!(
-            "Translation failure(s) when traslating {} with args {:?}: {:?}",
-            self.key.as_str(),
-            self.args,
-            errors
+            "Tried to translate non existing attribute {} on key {}",
+            attribute_name,
+            self.key.as_str()
           );
-          Cow::Borrowed(TRANSLATION_FAILED)
+          return missing_key_outcome(self.missing_key_mode, &self.key);
         }
-      }
+      },
+      None => match message.value() {
+        Some(value) => value,
+        None => {
+          error!(
+            "Tried to translate key {} which has no value (it may only have attributes)",
+            self.key.as_str()
+          );
+          return missing_key_outcome(self.missing_key_mode, &self.key);
+        }
+      },
+    };
+
+    let translated = self
+      .bundle
+      .format_pattern(pattern, self.args.as_ref(), &mut errors);
+
+    if errors.is_empty() {
+      Ok(translated)
+    } else {
+      error!(
+        "Translation failure(s) when traslating {} with args {:?}: {:?}",
+        self.key.as_str(),
+        self.args,
+        errors
+      );
+      missing_key_outcome(self.missing_key_mode, &self.key)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Creates (and empties) a scratch directory under the OS temp dir, unique
+  // to the calling test by `name`.
+  fn unique_temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust-utils-translator-test-{name}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  #[test]
+  fn load_directory_accepts_flat_ftl_file() {
+    let dir = unique_temp_dir("flat-file");
+    fs::write(dir.join("en.ftl"), "hello = Hello").unwrap();
+
+    let (translations, available_languages) =
+      Translator::load_directory(dir.to_str().unwrap()).unwrap();
+
+    assert!(translations.contains_key("en"));
+    assert!(available_languages.contains_key("en"));
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn load_directory_recurses_nested_directories() {
+    let dir = unique_temp_dir("nested-dirs");
+    let nested = dir.join("es").join("commands");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(dir.join("es").join("errors.ftl"), "oops = Ups").unwrap();
+    fs::write(nested.join("mod.ftl"), "hello = Hola").unwrap();
+
+    let (translations, _) = Translator::load_directory(dir.to_str().unwrap()).unwrap();
+    let bundle = translations.get("es").unwrap();
+
+    assert!(bundle.get_message("oops").is_some());
+    assert!(bundle.get_message("hello").is_some());
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn load_directory_ignores_non_ftl_files() {
+    let dir = unique_temp_dir("ignored-files");
+    fs::create_dir_all(dir.join("en")).unwrap();
+    fs::write(dir.join("en").join("hello.ftl"), "hello = Hi").unwrap();
+    fs::write(dir.join("en").join("README.md"), "not a translation").unwrap();
+    fs::write(dir.join("notes.txt"), "ignored top-level file").unwrap();
+
+    let (translations, _) = Translator::load_directory(dir.to_str().unwrap()).unwrap();
+    let bundle = translations.get("en").unwrap();
+
+    assert!(bundle.get_message("hello").is_some());
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  fn translator_with_languages(available: &[&str], default_language: &str) -> Translator {
+    let mut available_languages = HashMap::new();
+    let mut translations = HashMap::new();
+
+    for &language in available {
+      let identifier: LanguageIdentifier = language.parse().unwrap();
+      translations.insert(
+        language.to_string(),
+        Bundle::new_concurrent(vec![identifier.clone()]),
+      );
+      available_languages.insert(language.to_string(), identifier);
+    }
+
+    Translator {
+      translations,
+      available_languages,
+      default_language: default_language.to_string(),
+      missing_key_mode: MissingKeyMode::default(),
+      language_directory: String::new(),
+    }
+  }
+
+  #[test]
+  fn negotiate_exact_match_is_first() {
+    let translator = translator_with_languages(&["en", "es-MX"], "en");
+    assert_eq!(translator.negotiate("es-MX"), vec!["es-MX", "en"]);
+  }
+
+  #[test]
+  fn negotiate_strips_region_subtag() {
+    let translator = translator_with_languages(&["en", "es"], "en");
+    assert_eq!(translator.negotiate("es-MX"), vec!["es", "en"]);
+  }
+
+  #[test]
+  fn negotiate_falls_back_to_default_language() {
+    let translator = translator_with_languages(&["en", "fr"], "en");
+    assert_eq!(translator.negotiate("de"), vec!["en"]);
+  }
+
+  #[test]
+  fn negotiate_does_not_duplicate_default_language() {
+    let translator = translator_with_languages(&["en"], "en");
+    assert_eq!(translator.negotiate("en"), vec!["en"]);
+  }
+
+  #[test]
+  fn negotiate_sorts_sibling_region_variants_deterministically() {
+    let translator = translator_with_languages(&["en", "es-AR", "es-CL", "es-UY"], "en");
+    assert_eq!(
+      translator.negotiate("es-MX"),
+      vec!["es-AR", "es-CL", "es-UY", "en"]
+    );
+  }
+
+  struct TestKey(&'static str);
+
+  impl LanguageKey for TestKey {
+    fn as_str(&self) -> &'static str {
+      self.0
+    }
+  }
+
+  fn translator_with_mode(mode: MissingKeyMode) -> Translator {
+    let identifier: LanguageIdentifier = "en".parse().unwrap();
+    let mut bundle = Bundle::new_concurrent(vec![identifier.clone()]);
+    bundle
+      .add_resource(FluentResource::try_new("greeting = Hello".to_string()).unwrap())
+      .unwrap();
+
+    let mut translations = HashMap::new();
+    translations.insert("en".to_string(), bundle);
+
+    let mut available_languages = HashMap::new();
+    available_languages.insert("en".to_string(), identifier);
+
+    Translator {
+      translations,
+      available_languages,
+      default_language: "en".to_string(),
+      missing_key_mode: mode,
+      language_directory: String::new(),
+    }
+  }
+
+  #[test]
+  fn missing_key_mode_placeholder_returns_constant() {
+    let translator = translator_with_mode(MissingKeyMode::Placeholder);
+    let result = translator
+      .translate_without_args("en", TestKey("missing"))
+      .unwrap();
+    assert_eq!(result.as_ref(), TRANSLATION_FAILED);
+  }
+
+  #[test]
+  fn missing_key_mode_echo_key_returns_key_name() {
+    let translator = translator_with_mode(MissingKeyMode::EchoKey);
+    let result = translator
+      .translate_without_args("en", TestKey("missing"))
+      .unwrap();
+    assert_eq!(result.as_ref(), "missing");
+  }
+
+  #[test]
+  fn missing_key_mode_error_surfaces_missing_message() {
+    let translator = translator_with_mode(MissingKeyMode::Error);
+    let error = translator
+      .translate_without_args("en", TestKey("missing"))
+      .unwrap_err();
+    assert!(matches!(error, TranslatorError::MissingMessage(key) if key == "missing"));
+  }
+
+  #[test]
+  fn embed_translations_macro_expands_to_language_file_slices() {
+    let languages = embed_translations! {
+      "en" => ["fixtures/en.ftl"],
+    };
+
+    assert_eq!(languages.len(), 1);
+    assert_eq!(languages[0].0, "en");
+    assert_eq!(languages[0].1[0].0, "fixtures/en.ftl");
+
+    let translator = Translator::from_embedded(languages, "en".to_string()).unwrap();
+    let result = translator
+      .translate_without_args("en", TestKey("greeting"))
+      .unwrap();
+    assert_eq!(result.as_ref(), "Hello from an embedded resource");
+  }
+
+  #[test]
+  fn from_embedded_ignores_invalid_language_names() {
+    let languages: &[(&str, &[(&str, &str)])] =
+      &[("not-a-real-tag!", &[("hello.ftl", "hello = Hi")])];
+
+    match Translator::from_embedded(languages, "en".to_string()) {
+      Err(TranslatorError::DefaultLanguageMissing(default)) => assert_eq!(default, "en"),
+      _ => panic!("expected DefaultLanguageMissing"),
+    }
+  }
+
+  #[test]
+  fn from_embedded_errors_when_default_language_missing() {
+    let languages: &[(&str, &[(&str, &str)])] = &[("en", &[("hello.ftl", "hello = Hi")])];
+
+    match Translator::from_embedded(languages, "fr".to_string()) {
+      Err(TranslatorError::DefaultLanguageMissing(default)) => assert_eq!(default, "fr"),
+      _ => panic!("expected DefaultLanguageMissing"),
     }
   }
+
+  #[test]
+  fn reload_picks_up_content_changes() {
+    let dir = unique_temp_dir("reload-picks-up-changes");
+    fs::write(dir.join("en.ftl"), "greeting = Hello").unwrap();
+
+    let mut translator = Translator::new(dir.to_str().unwrap(), "en".to_string()).unwrap();
+    assert_eq!(
+      translator
+        .translate_without_args("en", TestKey("greeting"))
+        .unwrap()
+        .as_ref(),
+      "Hello"
+    );
+
+    fs::write(dir.join("en.ftl"), "greeting = Hi there").unwrap();
+    translator.reload().unwrap();
+
+    assert_eq!(
+      translator
+        .translate_without_args("en", TestKey("greeting"))
+        .unwrap()
+        .as_ref(),
+      "Hi there"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn reload_keeps_old_bundle_when_reload_fails() {
+    let dir = unique_temp_dir("reload-keeps-old-bundle");
+    fs::create_dir_all(dir.join("en")).unwrap();
+    fs::write(dir.join("en").join("greeting.ftl"), "greeting = Hello").unwrap();
+
+    let mut translator = Translator::new(dir.to_str().unwrap(), "en".to_string()).unwrap();
+
+    // Adds a second file to the `en` directory that redefines `greeting`,
+    // which makes `FluentBundle::add_resource` fail and the whole reload
+    // bail out before anything is swapped in.
+    fs::write(dir.join("en").join("more.ftl"), "greeting = Duplicate").unwrap();
+
+    assert!(translator.reload().is_err());
+
+    // The previously loaded bundle must still be queryable, since `reload`
+    // only swaps in the new translations once they are fully built.
+    assert_eq!(
+      translator
+        .translate_without_args("en", TestKey("greeting"))
+        .unwrap()
+        .as_ref(),
+      "Hello"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  fn translator_with_login_message(mode: MissingKeyMode) -> Translator {
+    let identifier: LanguageIdentifier = "en".parse().unwrap();
+    let mut bundle = Bundle::new_concurrent(vec![identifier.clone()]);
+    bundle
+      .add_resource(
+        FluentResource::try_new(
+          "login = Login\n    .placeholder = Enter your login".to_string(),
+        )
+        .unwrap(),
+      )
+      .unwrap();
+
+    let mut translations = HashMap::new();
+    translations.insert("en".to_string(), bundle);
+
+    let mut available_languages = HashMap::new();
+    available_languages.insert("en".to_string(), identifier);
+
+    Translator {
+      translations,
+      available_languages,
+      default_language: "en".to_string(),
+      missing_key_mode: mode,
+      language_directory: String::new(),
+    }
+  }
+
+  #[test]
+  fn attribute_formats_the_named_attribute_instead_of_the_value() {
+    let translator = translator_with_login_message(MissingKeyMode::Placeholder);
+    let result = translator
+      .translate("en", TestKey("login"))
+      .attribute("placeholder")
+      .build()
+      .unwrap();
+    assert_eq!(result.as_ref(), "Enter your login");
+  }
+
+  #[test]
+  fn build_without_an_attribute_still_formats_the_value() {
+    let translator = translator_with_login_message(MissingKeyMode::Placeholder);
+    let result = translator.translate("en", TestKey("login")).build().unwrap();
+    assert_eq!(result.as_ref(), "Login");
+  }
+
+  #[test]
+  fn attribute_with_unknown_name_hits_missing_key_outcome() {
+    let translator = translator_with_login_message(MissingKeyMode::Error);
+    let error = translator
+      .translate("en", TestKey("login"))
+      .attribute("aria-label")
+      .build()
+      .unwrap_err();
+    assert!(matches!(error, TranslatorError::MissingMessage(key) if key == "login"));
+  }
 }